@@ -1,6 +1,6 @@
 use std::io::{BufReader, BufWriter};
 
-use tsi::{ReadTsi, Tsi, WriteTsi, reader::TsiError};
+use tsi::{ReadTsi, Tsi, Vertex, WriteTsi, reader::TsiError};
 
 fn main() -> Result<(), TsiError> {
     let mut args = std::env::args().skip(1);
@@ -14,20 +14,17 @@ fn main() -> Result<(), TsiError> {
 
     let seconds = start.elapsed().as_secs_f64();
     println!("Successfully parsed {path:?} in {seconds:.3} s.");
-    println!("         box: {:?} nm", mesh.dimensions);
-    println!("    vertices: {}", mesh.vertices.len());
-    println!("   triangles: {}", mesh.triangles.len());
-    println!("  inclusions: {}", mesh.inclusions.len());
-    println!("  exclusions: {}", mesh.exclusions.len());
+    println!("         box: {:?} nm", mesh.dimensions());
+    println!("    vertices: {}", mesh.vertices().len());
+    println!("   triangles: {}", mesh.triangles().len());
+    println!("  inclusions: {}", mesh.inclusions().len());
+    println!("  exclusions: {}", mesh.exclusions().len());
 
     // Change some value, say the dimensions.
-    let mesh = {
-        let mut mesh = mesh;
-        for dim in &mut mesh.dimensions {
-            *dim *= 2.0;
-        }
-        mesh
-    };
+    let mut mesh = mesh;
+    for dim in mesh.dimensions_mut() {
+        *dim *= 2.0;
+    }
 
     // Write a tsi-formatted string.
     let mut buffer = Vec::new();
@@ -47,5 +44,14 @@ fn main() -> Result<(), TsiError> {
     let seconds = start.elapsed().as_secs_f64();
     println!("Successfully wrote to {path:?} in {seconds:.3} s.");
 
+    // A mesh can also be built from scratch with the public API, without
+    // ever going through a file.
+    let mut triangle = Tsi::new([10.0, 10.0, 10.0]);
+    triangle.push_vertex(Vertex::new([0.0, 0.0, 0.0], 0));
+    triangle.push_vertex(Vertex::new([1.0, 0.0, 0.0], 0));
+    triangle.push_vertex(Vertex::new([0.0, 1.0, 0.0], 0));
+    triangle.push_triangle(tsi::Triangle::new([0, 1, 2]))?;
+    println!("Hand-built a mesh with {} triangle(s).", triangle.triangles().len());
+
     Ok(())
 }
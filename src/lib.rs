@@ -1,4 +1,11 @@
-use std::io::{BufRead, BufReader, Read};
+pub mod obj;
+pub mod reader;
+pub mod writer;
+
+pub use reader::ReadTsi;
+pub use writer::WriteTsi;
+
+use reader::TsiError;
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct Tsi {
@@ -10,12 +17,129 @@ pub struct Tsi {
     exclusions: Vec<Exclusion>,
 }
 
+impl Tsi {
+    /// Creates an empty mesh with the given box `dimensions` (in nm).
+    pub fn new(dimensions: [f32; 3]) -> Self {
+        Self {
+            dimensions,
+            vertices: Vec::new(),
+            triangles: Vec::new(),
+            inclusions: Vec::new(),
+            exclusions: Vec::new(),
+        }
+    }
+
+    pub fn dimensions(&self) -> [f32; 3] {
+        self.dimensions
+    }
+
+    pub fn dimensions_mut(&mut self) -> &mut [f32; 3] {
+        &mut self.dimensions
+    }
+
+    pub fn vertices(&self) -> &[Vertex] {
+        &self.vertices
+    }
+
+    pub fn vertices_mut(&mut self) -> &mut [Vertex] {
+        &mut self.vertices
+    }
+
+    pub fn triangles(&self) -> &[Triangle] {
+        &self.triangles
+    }
+
+    pub fn triangles_mut(&mut self) -> &mut [Triangle] {
+        &mut self.triangles
+    }
+
+    pub fn inclusions(&self) -> &[Inclusion] {
+        &self.inclusions
+    }
+
+    pub fn inclusions_mut(&mut self) -> &mut [Inclusion] {
+        &mut self.inclusions
+    }
+
+    pub fn exclusions(&self) -> &[Exclusion] {
+        &self.exclusions
+    }
+
+    pub fn exclusions_mut(&mut self) -> &mut [Exclusion] {
+        &mut self.exclusions
+    }
+
+    /// Appends a vertex, returning the index it was assigned.
+    pub fn push_vertex(&mut self, vertex: Vertex) -> u32 {
+        let index = self.vertices.len() as u32;
+        self.vertices.push(vertex);
+        index
+    }
+
+    /// Appends a triangle, failing if any of its vertex indices don't name
+    /// an existing vertex.
+    pub fn push_triangle(&mut self, triangle: Triangle) -> Result<(), TsiError> {
+        for index in triangle.vertices {
+            self.check_vertex_index("triangle vertex", index)?;
+        }
+        self.triangles.push(triangle);
+        Ok(())
+    }
+
+    /// Appends an inclusion, normalizing `vector` to unit length exactly as
+    /// [`ReadTsi::parse`] does, and failing if `vertex_index` doesn't name
+    /// an existing vertex.
+    pub fn add_inclusion(
+        &mut self,
+        ty: i32,
+        vertex_index: u32,
+        vector: [f32; 2],
+    ) -> Result<(), TsiError> {
+        self.check_vertex_index("inclusion vertex", vertex_index)?;
+        let [x, y] = vector;
+        let norm = f32::sqrt(x.powi(2) + y.powi(2));
+        let vector = if norm > 0.0 { [x / norm, y / norm] } else { [0.0, 0.0] };
+        self.inclusions.push(Inclusion { ty, vertex_index, vector });
+        Ok(())
+    }
+
+    /// Appends an exclusion, failing if `vertex_index` doesn't name an
+    /// existing vertex.
+    pub fn add_exclusion(&mut self, vertex_index: u32, radius: f32) -> Result<(), TsiError> {
+        self.check_vertex_index("exclusion vertex", vertex_index)?;
+        self.exclusions.push(Exclusion { vertex_index, radius });
+        Ok(())
+    }
+
+    fn check_vertex_index(&self, thing: &'static str, index: u32) -> Result<(), TsiError> {
+        if (index as usize) < self.vertices.len() {
+            Ok(())
+        } else {
+            Err(TsiError::IndexOutOfRange { thing, index, len: self.vertices.len() })
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct Vertex {
     position: [f32; 3],
     domain: i32,
 }
 
+impl Vertex {
+    pub fn new(position: [f32; 3], domain: i32) -> Self {
+        Self { position, domain }
+    }
+
+    pub fn position(&self) -> [f32; 3] {
+        self.position
+    }
+
+    pub fn domain(&self) -> i32 {
+        self.domain
+    }
+}
+
 // In the TS2CG implementation, this is an `int`.
 type VertexIndex = u32;
 
@@ -24,6 +148,16 @@ pub struct Triangle {
     vertices: [VertexIndex; 3],
 }
 
+impl Triangle {
+    pub fn new(vertices: [u32; 3]) -> Self {
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> [u32; 3] {
+        self.vertices
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct Inclusion {
     ty: i32,
@@ -31,227 +165,33 @@ pub struct Inclusion {
     vector: [f32; 2],
 }
 
+impl Inclusion {
+    pub fn ty(&self) -> i32 {
+        self.ty
+    }
+
+    pub fn vertex_index(&self) -> u32 {
+        self.vertex_index
+    }
+
+    pub fn vector(&self) -> [f32; 2] {
+        self.vector
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct Exclusion {
     vertex_index: VertexIndex,
     radius: f32,
 }
 
-impl Tsi {
-    pub fn parse(reader: impl Read) -> std::io::Result<Self> {
-        let reader = BufReader::new(reader);
-        let mut lines = reader.lines();
-
-        let mut version = None;
-        let mut dimensions = None;
-        let mut vertices = Vec::new();
-        let mut triangles = Vec::new();
-        let mut inclusions = Vec::new();
-        let mut exclusions = Vec::new();
-        loop {
-            let Some(line) = lines.next().transpose()? else { break };
-            let mut words = line.split_whitespace();
-            let keyword = words.next().expect("expected keyword");
-
-            match keyword {
-                "version" => version = Some(words.next().expect("tsi version tag").to_string()),
-                "box" => {
-                    let x = words
-                        .next()
-                        .expect("box dimensions x value")
-                        .parse()
-                        .expect("could not parse box dimensions x value");
-                    let y = words
-                        .next()
-                        .expect("box dimensions y value")
-                        .parse()
-                        .expect("could not parse box dimensions y value");
-                    let z = words
-                        .next()
-                        .expect("box dimensions z value")
-                        .parse()
-                        .expect("could not parse box dimensions z value");
-                    dimensions = Some([x, y, z]);
-                }
-
-                // Find out what section is coming up.
-                "vertex" => {
-                    let n = words
-                        .next()
-                        .expect("number of vertices")
-                        .parse()
-                        .expect("could not parse number of vertices");
-                    vertices = Vec::with_capacity(n as usize);
-                    for idx in 0..n {
-                        let line = lines.next().expect("vertex line")?;
-                        let mut words = line.split_whitespace();
-                        let found_idx = words
-                            .next()
-                            .expect("vertex index")
-                            .parse()
-                            .expect("could not parse vertex index");
-                        assert_eq!(
-                            idx, found_idx,
-                            "incorrectly indexed vertex: found {found_idx}, expected {idx}"
-                        );
-                        let x = words
-                            .next()
-                            .expect("vertex position x value")
-                            .parse()
-                            .expect("could not parse vertex position x value");
-                        let y = words
-                            .next()
-                            .expect("vertex position y value")
-                            .parse()
-                            .expect("could not parse vertex position y value");
-                        let z = words
-                            .next()
-                            .expect("vertex position z value")
-                            .parse()
-                            .expect("could not parse vertex position z value");
-                        let position = [x, y, z];
-                        let domain = words
-                            .next()
-                            .map(|v| v.parse().expect("could not parse vertex domain value"))
-                            .unwrap_or_default();
-                        vertices.push(Vertex { position, domain });
-                    }
-                }
-                "triangle" => {
-                    let n = words
-                        .next()
-                        .expect("number of triangles")
-                        .parse()
-                        .expect("could not parse number of triangles");
-                    {
-                        triangles = Vec::with_capacity(n as usize);
-                        for idx in 0..n {
-                            let line = lines.next().expect("triangle line")?;
-                            let mut words = line.split_whitespace();
-                            let found_idx = words
-                                .next()
-                                .expect("triangle index")
-                                .parse()
-                                .expect("could not parse triangle index");
-                            assert_eq!(
-                                idx, found_idx,
-                                "incorrectly indexed triangle: found {found_idx}, expected {idx}"
-                            );
-                            let a = words
-                                .next()
-                                .expect("triangle vertex index")
-                                .parse()
-                                .expect("could not parse triangle vertex index");
-                            let b = words
-                                .next()
-                                .expect("second triangle vertex index")
-                                .parse()
-                                .expect("could not parse second triangle vertex index");
-                            let c = words
-                                .next()
-                                .expect("third triangle vertex index")
-                                .parse()
-                                .expect("could not parse third triangle vertex index");
-                            let vertices = [a, b, c];
-                            triangles.push(Triangle { vertices });
-                        }
-                    }
-                }
-                "inclusion" => {
-                    let n = words
-                        .next()
-                        .expect("number of inclusions")
-                        .parse()
-                        .expect("could not parse number of inclusions");
-                    inclusions = Vec::with_capacity(n as usize);
-                    for idx in 0..n {
-                        let line = lines.next().expect("inclusion line")?;
-                        let mut words = line.split_whitespace();
-                        let found_idx = words
-                            .next()
-                            .expect("inclusion index")
-                            .parse()
-                            .expect("could not parse inclusion index");
-                        assert_eq!(
-                            idx, found_idx,
-                            "incorrectly indexed inclusion: found {found_idx}, expected {idx}"
-                        );
-                        let ty = words
-                            .next()
-                            .expect("inclusion type index")
-                            .parse()
-                            .expect("could not parse inclusion type index");
-                        let vertex_index = words
-                            .next()
-                            .expect("inclusino vertex index")
-                            .parse()
-                            .expect("could not parse inclusino vertex index");
-                        let x = words
-                            .next()
-                            .expect("inclusion vector x value")
-                            .parse::<f32>()
-                            .expect("could not parse inclusion vector x value");
-                        let y = words
-                            .next()
-                            .expect("inclusion vector x value")
-                            .parse::<f32>()
-                            .expect("could not parse inclusion vector y value");
-                        let norm = f32::sqrt(x.powi(2) + y.powi(2));
-                        let vector = [x / norm, y / norm];
-                        inclusions.push(Inclusion { ty, vertex_index, vector });
-                    }
-                }
-                "exclusion" => {
-                    let n = words
-                        .next()
-                        .expect("number of exclusions")
-                        .parse()
-                        .expect("could not parse number of exclusions");
-                    exclusions = Vec::with_capacity(n as usize);
-                    for idx in 0..n {
-                        let line = lines.next().expect("exclusion line")?;
-                        let mut words = line.split_whitespace();
-                        let found_idx = words
-                            .next()
-                            .expect("exclusion vertex index")
-                            .parse()
-                            .expect("could not parse inclusion exclusion index");
-                        assert_eq!(
-                            idx, found_idx,
-                            "incorrectly indexed exclusion: found {found_idx}, expected {idx}"
-                        );
-                        let vertex_index = words
-                            .next()
-                            .expect("vertex index")
-                            .parse()
-                            .expect("could not parse exclusion vertex index");
-                        let radius = words
-                            .next()
-                            .expect("exclusion exclusion radius")
-                            .parse()
-                            .expect("could not parse exclusion radius");
-                        exclusions.push(Exclusion { vertex_index, radius });
-                    }
-                }
-                unknown => panic!("encountered unknown keyword: {unknown}"),
-            }
-        }
+impl Exclusion {
+    pub fn vertex_index(&self) -> u32 {
+        self.vertex_index
+    }
 
-        const VERSION: &str = "1.1";
-        match version {
-            Some(version) if version == VERSION => {}
-            Some(version) => {
-                panic!("found unsupported version {version}, expected version {VERSION}")
-            }
-            None => panic!("version must be specified, expected version {VERSION}"),
-        }
-        Ok(Tsi {
-            dimensions: dimensions.expect("box dimensions must be specified"),
-            vertices,
-            triangles,
-            inclusions,
-            exclusions,
-        })
+    pub fn radius(&self) -> f32 {
+        self.radius
     }
 }
 
@@ -260,21 +200,30 @@ mod tests {
     use super::*;
 
     #[test]
-    fn basic() {
-        let src = "version 1.1
-box 50.000     50.000     50.000
-vertex 3
-0       21.4    33.8    32.7    0
-1       38.1    26.1    32.3    0
-2       40.9    24.2    19.9    0
-triangle 1
-0          1       2       0    1
-inclusion 3
-0         1       22       0    1
-1         1        5       0    1
-2         2       30       0    1";
-        let tsi = Tsi::parse(src.as_bytes()).unwrap();
-        dbg!(tsi);
-        panic!();
+    fn build_a_mesh_by_hand() {
+        let mut tsi = Tsi::new([50.0, 50.0, 50.0]);
+        tsi.push_vertex(Vertex::new([0.0, 0.0, 0.0], 0));
+        tsi.push_vertex(Vertex::new([1.0, 0.0, 0.0], 0));
+        tsi.push_vertex(Vertex::new([0.0, 1.0, 0.0], 0));
+
+        tsi.push_triangle(Triangle::new([0, 1, 2])).unwrap();
+        tsi.add_inclusion(1, 0, [3.0, 4.0]).unwrap();
+        tsi.add_exclusion(1, 5.0).unwrap();
+
+        assert_eq!(tsi.vertices().len(), 3);
+        assert_eq!(tsi.triangles().len(), 1);
+        // The inclusion vector is normalized to unit length on insertion.
+        assert_eq!(tsi.inclusions()[0].vector(), [0.6, 0.8]);
+        assert_eq!(tsi.exclusions()[0].radius(), 5.0);
+    }
+
+    #[test]
+    fn push_triangle_rejects_out_of_range_vertex() {
+        let mut tsi = Tsi::new([50.0, 50.0, 50.0]);
+        tsi.push_vertex(Vertex::new([0.0, 0.0, 0.0], 0));
+
+        let result = tsi.push_triangle(Triangle::new([0, 1, 2]));
+        assert!(matches!(result, Err(TsiError::IndexOutOfRange { index: 1, len: 1, .. })));
     }
 }
+
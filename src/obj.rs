@@ -0,0 +1,305 @@
+//! Wavefront OBJ import and export for [`Tsi`] meshes.
+//!
+//! This is a general-purpose interchange format, kept separate from the
+//! native `.tsi` I/O in [`crate::reader`] and [`crate::writer`], so a mesh
+//! can be opened in (and brought back from) Blender, MeshLab, or any other
+//! standard 3D viewer.
+//!
+//! OBJ has no slot for the box `dimensions`, per-vertex `domain`,
+//! `inclusions`, or `exclusions`, so they are written out as structured
+//! `# tsi_*` comment lines that a plain OBJ viewer will simply ignore, and
+//! [`Tsi::from_obj`] recovers them from those comments when present, falling
+//! back to sensible defaults (an all-zero domain, no inclusions or
+//! exclusions, the bounding box of the vertices) when they are absent.
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+use crate::reader::{MissingItem, ParseValue, TsiError};
+use crate::writer::round_to_precision;
+use crate::{Exclusion, Inclusion, Triangle, Tsi, Vertex, VertexIndex};
+
+/// Writes a [`Tsi`] mesh out as Wavefront `.obj`.
+pub trait WriteObj {
+    fn write(&self, writer: impl Write) -> io::Result<()>;
+}
+
+impl WriteObj for Tsi {
+    fn write(&self, mut writer: impl Write) -> io::Result<()> {
+        let [x, y, z] = self.dimensions.map(round_to_precision);
+        // Not a real OBJ statement, but lets `Tsi::from_obj` recover the box
+        // that OBJ has no slot for.
+        writeln!(writer, "# tsi_box {x} {y} {z}")?;
+
+        for vertex in &self.vertices {
+            let Vertex { position, domain } = vertex;
+            let [x, y, z] = position.map(round_to_precision);
+            writeln!(writer, "v {x} {y} {z}")?;
+            writeln!(writer, "# tsi_domain {domain}")?;
+        }
+
+        for triangle in &self.triangles {
+            let Triangle { vertices: [a, b, c] } = triangle;
+            // OBJ face indices are 1-based, `Triangle::vertices` are 0-based.
+            writeln!(writer, "f {} {} {}", a + 1, b + 1, c + 1)?;
+        }
+
+        for inclusion in &self.inclusions {
+            let Inclusion { ty, vertex_index, vector: [vx, vy] } = inclusion;
+            writeln!(writer, "# tsi_inclusion {ty} {vertex_index} {vx} {vy}")?;
+        }
+
+        for exclusion in &self.exclusions {
+            let Exclusion { vertex_index, radius } = exclusion;
+            let radius = round_to_precision(*radius);
+            writeln!(writer, "# tsi_exclusion {vertex_index} {radius}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Tsi {
+    /// Parses a Wavefront `.obj` mesh into a [`Tsi`].
+    ///
+    /// OBJ has no box, so `dimensions` is, in order of preference: the
+    /// caller-supplied value, the `# tsi_box` comment written by
+    /// [`WriteObj::write`], or failing both, the axis-aligned bounding box
+    /// of the imported vertices. Quad and n-gon faces are triangulated by
+    /// fanning out from their first vertex. When `dedup` is set, vertices at
+    /// coincident positions (within `round_to_precision` tolerance) are
+    /// merged into one, since OBJ exporters commonly repeat shared vertices
+    /// across faces. The `# tsi_domain`, `# tsi_inclusion`, and
+    /// `# tsi_exclusion` comments emitted by [`WriteObj::write`] are
+    /// recovered if present; otherwise every vertex gets domain `0` and no
+    /// inclusions or exclusions are added.
+    pub fn from_obj(
+        reader: impl Read,
+        dimensions: Option<[f32; 3]>,
+        dedup: bool,
+    ) -> Result<Self, TsiError> {
+        let reader = BufReader::new(reader);
+
+        let mut dimensions_comment = None;
+        let mut positions = Vec::new();
+        let mut domains = Vec::new();
+        let mut faces = Vec::new();
+        let mut raw_inclusions = Vec::new();
+        let mut raw_exclusions = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            let mut words = line.split_whitespace();
+            let Some(keyword) = words.next() else { continue };
+
+            match keyword {
+                "v" => {
+                    let x = words.next().parse_value("obj vertex x")?;
+                    let y = words.next().parse_value("obj vertex y")?;
+                    let z = words.next().parse_value("obj vertex z")?;
+                    positions.push([x, y, z]);
+                    domains.push(0);
+                }
+                "f" => {
+                    let mut face = Vec::new();
+                    for token in words {
+                        // An `f` token may be `v`, `v/vt`, `v/vt/vn`, or
+                        // `v//vn`; only the leading vertex index matters here.
+                        let v = token.split('/').next().ok_or(TsiError::Missing(
+                            MissingItem::Value("obj face vertex index"),
+                        ))?;
+                        let v: i64 = v.parse().map_err(TsiError::ParseInt)?;
+                        // OBJ indices are 1-based; negative indices count
+                        // backward from the current end of the vertex list.
+                        let index = if v < 0 {
+                            positions.len() as i64 + v
+                        } else {
+                            v - 1
+                        };
+                        if index < 0 {
+                            return Err(TsiError::NegativeIndex {
+                                thing: "obj face vertex",
+                                index,
+                            });
+                        }
+                        face.push(index as VertexIndex);
+                    }
+                    faces.push(face);
+                }
+                "#" => match words.next() {
+                    Some("tsi_box") => {
+                        let x = words.next().parse_value("tsi_box x")?;
+                        let y = words.next().parse_value("tsi_box y")?;
+                        let z = words.next().parse_value("tsi_box z")?;
+                        dimensions_comment = Some([x, y, z]);
+                    }
+                    Some("tsi_domain") => {
+                        let domain = words.next().parse_value("tsi_domain")?;
+                        if let Some(last) = domains.last_mut() {
+                            *last = domain;
+                        }
+                    }
+                    Some("tsi_inclusion") => {
+                        let ty = words.next().parse_value("tsi_inclusion type")?;
+                        let vertex_index = words.next().parse_value("tsi_inclusion vertex index")?;
+                        let x: f32 = words.next().parse_value("tsi_inclusion vector x")?;
+                        let y: f32 = words.next().parse_value("tsi_inclusion vector y")?;
+                        raw_inclusions.push((ty, vertex_index, x, y));
+                    }
+                    Some("tsi_exclusion") => {
+                        let vertex_index = words.next().parse_value("tsi_exclusion vertex index")?;
+                        let radius = words.next().parse_value("tsi_exclusion radius")?;
+                        raw_exclusions.push((vertex_index, radius));
+                    }
+                    // A plain comment; nothing to recover from it.
+                    _ => {}
+                },
+                // Statements with no bearing on geometry are ignored.
+                "vt" | "vn" | "o" | "g" | "s" | "mtllib" | "usemtl" => {}
+                unknown => return Err(TsiError::UnexpectedKeyword(unknown.to_string())),
+            }
+        }
+
+        let (vertices, remap) = if dedup {
+            let (vertices, remap) = dedup_positions(&positions, &domains);
+            (vertices, Some(remap))
+        } else {
+            let vertices = positions
+                .into_iter()
+                .zip(domains)
+                .map(|(position, domain)| Vertex { position: position.map(round_to_precision), domain })
+                .collect();
+            (vertices, None)
+        };
+        let resolve = |v: VertexIndex| match &remap {
+            Some(remap) => remap[v as usize],
+            None => v,
+        };
+
+        let mut triangles = Vec::new();
+        for face in faces {
+            // Fan triangulation: (v0, v1, v2), (v0, v2, v3), ...
+            for i in 1..face.len().saturating_sub(1) {
+                let [v0, vi, vi1] = [face[0], face[i], face[i + 1]];
+                triangles.push(Triangle { vertices: [resolve(v0), resolve(vi), resolve(vi1)] });
+            }
+        }
+
+        let inclusions = raw_inclusions
+            .into_iter()
+            .map(|(ty, vertex_index, x, y)| {
+                let norm = f32::sqrt(x.powi(2) + y.powi(2));
+                let vector = if norm > 0.0 { [x / norm, y / norm] } else { [0.0, 0.0] };
+                Inclusion { ty, vertex_index: resolve(vertex_index), vector }
+            })
+            .collect();
+        let exclusions = raw_exclusions
+            .into_iter()
+            .map(|(vertex_index, radius)| Exclusion { vertex_index: resolve(vertex_index), radius })
+            .collect();
+
+        let dimensions =
+            dimensions.or(dimensions_comment).unwrap_or_else(|| bounding_box(&vertices));
+
+        Ok(Tsi { dimensions, vertices, triangles, inclusions, exclusions })
+    }
+}
+
+/// Merges vertices at coincident (rounded) positions, returning the
+/// deduplicated vertices (keeping the domain of the first occurrence of
+/// each position) and a `raw index -> deduplicated index` remap.
+fn dedup_positions(positions: &[[f32; 3]], domains: &[i32]) -> (Vec<Vertex>, Vec<VertexIndex>) {
+    let mut vertices = Vec::new();
+    let mut remap = Vec::with_capacity(positions.len());
+    let mut seen: HashMap<(i32, i32, i32), VertexIndex> = HashMap::new();
+
+    for (position, &domain) in positions.iter().zip(domains) {
+        let [x, y, z] = position.map(round_to_precision);
+        // Key on the rounded coordinates in thousandths of a nm so coincident
+        // vertices, up to `round_to_precision`, hash to the same bucket.
+        let key = ((x * 1e3) as i32, (y * 1e3) as i32, (z * 1e3) as i32);
+        let index = *seen.entry(key).or_insert_with(|| {
+            let index = vertices.len() as VertexIndex;
+            vertices.push(Vertex { position: [x, y, z], domain });
+            index
+        });
+        remap.push(index);
+    }
+
+    (vertices, remap)
+}
+
+/// Computes the axis-aligned bounding box extent of a set of vertices.
+fn bounding_box(vertices: &[Vertex]) -> [f32; 3] {
+    let mut min = [f32::INFINITY; 3];
+    let mut max = [f32::NEG_INFINITY; 3];
+    for vertex in vertices {
+        for axis in 0..3 {
+            min[axis] = min[axis].min(vertex.position[axis]);
+            max[axis] = max[axis].max(vertex.position[axis]);
+        }
+    }
+    std::array::from_fn(|axis| max[axis] - min[axis])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_sample_tsi() -> Tsi {
+        Tsi {
+            dimensions: [50.0, 50.0, 50.0],
+            vertices: vec![
+                Vertex { position: [21.4, 33.8, 32.7], domain: 0 },
+                Vertex { position: [38.1, 26.1, 32.3], domain: 1 },
+                Vertex { position: [40.9, 24.2, 19.9], domain: 0 },
+            ],
+            triangles: vec![Triangle { vertices: [0, 1, 2] }],
+            inclusions: vec![Inclusion { ty: 1, vertex_index: 2, vector: [0.0, 1.0] }],
+            exclusions: vec![Exclusion { vertex_index: 0, radius: 5.0 }],
+        }
+    }
+
+    /// Prove that `from_obj(write(data)) == data`, including the metadata
+    /// that plain OBJ has no slot for.
+    #[test]
+    fn obj_round_trip() {
+        let original = create_sample_tsi();
+
+        let mut buffer = Vec::new();
+        original.write(&mut buffer).unwrap(); // Safe, because writing to a Vec can't fail.
+
+        let recovered = Tsi::from_obj(buffer.as_slice(), None, false).unwrap();
+
+        assert_eq!(original.dimensions, recovered.dimensions);
+        assert_eq!(original.vertices, recovered.vertices);
+        assert_eq!(original.triangles, recovered.triangles);
+        assert_eq!(original.inclusions, recovered.inclusions);
+        assert_eq!(original.exclusions, recovered.exclusions);
+    }
+
+    #[test]
+    fn from_obj_without_metadata_falls_back_to_defaults() {
+        let src = "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n";
+        let tsi = Tsi::from_obj(src.as_bytes(), None, false).unwrap();
+
+        assert_eq!(tsi.vertices.len(), 3);
+        assert!(tsi.vertices.iter().all(|v| v.domain == 0));
+        assert!(tsi.inclusions.is_empty());
+        assert!(tsi.exclusions.is_empty());
+        // No `# tsi_box` comment, so it falls back to the bounding box.
+        assert_eq!(tsi.dimensions, [1.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn from_obj_rejects_face_index_zero() {
+        let src = "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 0 1 2\n";
+        let err = Tsi::from_obj(src.as_bytes(), None, false).unwrap_err();
+        assert!(matches!(err, TsiError::NegativeIndex { thing: "obj face vertex", index: -1 }));
+    }
+
+    #[test]
+    fn from_obj_rejects_out_of_range_negative_relative_index() {
+        let src = "v 0 0 0\nf -5\n";
+        let err = Tsi::from_obj(src.as_bytes(), None, false).unwrap_err();
+        assert!(matches!(err, TsiError::NegativeIndex { thing: "obj face vertex", index: -4 }));
+    }
+}
@@ -16,12 +16,175 @@ pub enum TsiError {
     InvalidVersion(String),
     IndexMismatch { found: u32, expected: u32, thing: &'static str },
     UnexpectedKeyword(String),
+    /// A `Triangle.vertices`, `Inclusion.vertex_index`, or
+    /// `Exclusion.vertex_index` that doesn't name an actual vertex, or a
+    /// declared record index at or beyond its section's count. `len` is the
+    /// number of vertices or records that the index had to fall below.
+    IndexOutOfRange { thing: &'static str, index: u32, len: usize },
+    /// The same index declared twice within a section, or a triangle that
+    /// names the same vertex more than once.
+    DuplicateIndex { thing: &'static str, index: u32 },
+    /// A computed index (e.g. a 1-based or negative-relative OBJ index) that
+    /// resolves to less than zero instead of naming an actual item.
+    NegativeIndex { thing: &'static str, index: i64 },
+    /// Attaches the line (and, where known, the byte offset of the specific
+    /// token within it) on which `source` occurred, so callers can point a
+    /// user at the offending field instead of a bare parse error.
+    At { line: usize, offset: Option<usize>, source: Box<TsiError> },
 }
 
 const fn missing_item_value(s: &'static str) -> TsiError {
     TsiError::Missing(MissingItem::Value(s))
 }
 
+/// Wraps the error of `result`, if any, with the line it occurred on.
+fn at<T>(line: usize, result: Result<T, TsiError>) -> Result<T, TsiError> {
+    result.map_err(|source| TsiError::At { line, offset: None, source: Box::new(source) })
+}
+
+/// Wraps the error of `result`, if any, with the line and byte offset of the
+/// token it occurred on.
+fn at_offset<T>(line: usize, offset: usize, result: Result<T, TsiError>) -> Result<T, TsiError> {
+    result.map_err(|source| TsiError::At { line, offset: Some(offset), source: Box::new(source) })
+}
+
+/// A whitespace-delimited word from a `.tsi` line, with the byte offset of
+/// its first character within that line, so a parse failure can point at
+/// the exact field instead of just the line.
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    text: &'a str,
+    offset: usize,
+}
+
+/// Splits a line into its whitespace-delimited [`Token`]s.
+fn tokenize(line: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in line.char_indices() {
+        match (c.is_whitespace(), start) {
+            (false, None) => start = Some(i),
+            (true, Some(s)) => {
+                tokens.push(Token { text: &line[s..i], offset: s });
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        tokens.push(Token { text: &line[s..], offset: s });
+    }
+    tokens
+}
+
+/// Parses the token at `idx`, attributing any failure to `line_no` and,
+/// once a token is found, its exact byte offset.
+fn field<T>(
+    tokens: &[Token],
+    idx: usize,
+    desc: &'static str,
+    line_no: usize,
+) -> Result<T, TsiError>
+where
+    T: std::str::FromStr,
+    TsiError: From<T::Err>,
+{
+    let Some(token) = tokens.get(idx) else {
+        return at(line_no, Err(missing_item_value(desc)));
+    };
+    at_offset(line_no, token.offset, token.text.parse().map_err(TsiError::from))
+}
+
+/// Places each `(declared_idx, line_no, item)` into the slot its author
+/// intended, instead of the order it happened to appear in the file, so a
+/// section with out-of-order declarations still ends up with `vertices[i]`
+/// actually holding record `i`'s data. Slots that no record filled (a
+/// record that failed to parse, or an index never declared) are dropped
+/// from the result, so every record after such a gap moves down one slot.
+/// Reports an `IndexMismatch` for every declaration whose index differs
+/// from its position among the given `items`, and either a
+/// `DuplicateIndex` or an `IndexOutOfRange` for a declared index that can't
+/// be placed at all.
+fn place_by_declared_index<T>(
+    n: u32,
+    thing: &'static str,
+    items: Vec<(u32, usize, T)>,
+    errors: &mut Vec<TsiError>,
+) -> Vec<T> {
+    let mut slots: Vec<Option<T>> = (0..n).map(|_| None).collect();
+    for (order_pos, (declared_idx, line_no, item)) in items.into_iter().enumerate() {
+        if declared_idx != order_pos as u32 {
+            errors.push(TsiError::At {
+                line: line_no,
+                offset: None,
+                source: Box::new(TsiError::IndexMismatch {
+                    found: declared_idx,
+                    expected: order_pos as u32,
+                    thing,
+                }),
+            });
+        }
+        match slots.get_mut(declared_idx as usize) {
+            Some(slot @ None) => *slot = Some(item),
+            Some(Some(_)) => errors.push(TsiError::At {
+                line: line_no,
+                offset: None,
+                source: Box::new(TsiError::DuplicateIndex { thing, index: declared_idx }),
+            }),
+            None => errors.push(TsiError::At {
+                line: line_no,
+                offset: None,
+                source: Box::new(TsiError::IndexOutOfRange { thing, index: declared_idx, len: n as usize }),
+            }),
+        }
+    }
+    slots.into_iter().flatten().collect()
+}
+
+/// Controls how tolerant [`Tsi::parse_with`] is of unrecognized input.
+#[derive(Debug, Clone, Copy)]
+pub struct ParseOptions {
+    /// When `true`, an unrecognized section keyword is a hard error,
+    /// matching [`ReadTsi::parse`]. When `false`, such lines are skipped
+    /// and collected instead, so forward-compatible extensions to the
+    /// format don't break existing readers.
+    pub strict: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self { strict: true }
+    }
+}
+
+/// Strips a `#`-prefixed comment, whether it covers the whole line or
+/// trails after content on it.
+fn strip_comment(line: &str) -> &str {
+    match line.find('#') {
+        Some(i) => &line[..i],
+        None => line,
+    }
+}
+
+/// Reads the next line that is neither blank nor comment-only, stripping
+/// any trailing `#` comment from it, and returns it together with its
+/// 1-based line number. Advances `line_no` for every raw line consumed,
+/// including skipped ones.
+fn next_line<R: BufRead>(
+    lines: &mut std::io::Lines<R>,
+    line_no: &mut usize,
+) -> std::io::Result<Option<(usize, String)>> {
+    loop {
+        let Some(line) = lines.next().transpose()? else { return Ok(None) };
+        *line_no += 1;
+        let stripped = strip_comment(&line).trim_end().to_string();
+        if stripped.trim().is_empty() {
+            continue;
+        }
+        return Ok(Some((*line_no, stripped)));
+    }
+}
+
 /// Description of a missing item while parsing a `tsi` file.
 #[derive(Debug)]
 pub enum MissingItem {
@@ -49,6 +212,17 @@ impl std::fmt::Display for TsiError {
                 write!(f, "incorrect {thing} index: found {found}, expected {expected}")
             }
             Self::UnexpectedKeyword(k) => write!(f, "encountered unknown keyword: {k}"),
+            Self::IndexOutOfRange { thing, index, len } => {
+                write!(f, "{thing} index {index} is out of range (only {len} available)")
+            }
+            Self::DuplicateIndex { thing, index } => write!(f, "duplicate {thing} index {index}"),
+            Self::NegativeIndex { thing, index } => {
+                write!(f, "{thing} index {index} is negative")
+            }
+            Self::At { line, offset: Some(offset), source } => {
+                write!(f, "line {line}, column {offset}: {source}")
+            }
+            Self::At { line, offset: None, source } => write!(f, "line {line}: {source}"),
         }
     }
 }
@@ -84,7 +258,7 @@ impl From<ParseFloatError> for TsiError {
     }
 }
 
-trait ParseValue<T> {
+pub(crate) trait ParseValue<T> {
     fn parse_value(self, desc: &'static str) -> Result<T, TsiError>;
 }
 
@@ -99,7 +273,7 @@ where
 }
 
 mod items {
-    use super::{ParseValue, TsiError};
+    use super::{at, field, tokenize, TsiError};
     use crate::{Exclusion, Inclusion, Triangle, Vertex};
 
     const fn check_index(thing: &'static str, found: u32, expected: u32) -> Result<(), TsiError> {
@@ -110,56 +284,120 @@ mod items {
         }
     }
 
-    pub fn parse_vertex_line(line: &str, expected_idx: u32) -> Result<Vertex, TsiError> {
-        let mut words = line.split_whitespace();
-        let found_idx = words.next().parse_value("vertex index")?;
-        check_index("vertex", found_idx, expected_idx)?;
-
-        let x = words.next().parse_value("vertex x")?;
-        let y = words.next().parse_value("vertex y")?;
-        let z = words.next().parse_value("vertex z")?;
+    fn vertex_fields(tokens: &[super::Token], line_no: usize) -> Result<(u32, Vertex), TsiError> {
+        let found_idx = field(tokens, 0, "vertex index", line_no)?;
+        let x = field(tokens, 1, "vertex x", line_no)?;
+        let y = field(tokens, 2, "vertex y", line_no)?;
+        let z = field(tokens, 3, "vertex z", line_no)?;
         // The domain may be absent, implying it is set to 0.
-        let domain = words.next().map(|v| v.parse()).transpose()?.unwrap_or(0);
+        let domain = match tokens.get(4) {
+            Some(token) => {
+                super::at_offset(line_no, token.offset, token.text.parse().map_err(TsiError::from))?
+            }
+            None => 0,
+        };
+        Ok((found_idx, Vertex { position: [x, y, z], domain }))
+    }
 
-        Ok(Vertex { position: [x, y, z], domain })
+    fn triangle_fields(
+        tokens: &[super::Token],
+        line_no: usize,
+    ) -> Result<(u32, Triangle), TsiError> {
+        let found_idx = field(tokens, 0, "triangle index", line_no)?;
+        let a = field(tokens, 1, "first triangle vertex index", line_no)?;
+        let b = field(tokens, 2, "second triangle vertex index", line_no)?;
+        let c = field(tokens, 3, "third triangle vertex index", line_no)?;
+        Ok((found_idx, Triangle { vertices: [a, b, c] }))
     }
 
-    pub fn parse_triangle_line(line: &str, expected_idx: u32) -> Result<Triangle, TsiError> {
-        let mut words = line.split_whitespace();
-        let found_idx = words.next().parse_value("triangle index")?;
-        check_index("triangle", found_idx, expected_idx)?;
+    fn inclusion_fields(
+        tokens: &[super::Token],
+        line_no: usize,
+    ) -> Result<(u32, Inclusion), TsiError> {
+        let found_idx = field(tokens, 0, "inclusion index", line_no)?;
+        let ty = field(tokens, 1, "inclusion type", line_no)?;
+        let vertex_index = field(tokens, 2, "inclusion vertex index", line_no)?;
+        let x: f32 = field(tokens, 3, "inclusion vector x", line_no)?;
+        let y: f32 = field(tokens, 4, "inclusion vector y", line_no)?;
+        let norm = f32::sqrt(x.powi(2) + y.powi(2));
+        let vector = if norm > 0.0 { [x / norm, y / norm] } else { [0.0, 0.0] };
+        Ok((found_idx, Inclusion { ty, vertex_index, vector }))
+    }
 
-        let a = words.next().parse_value("first triangle vertex index")?;
-        let b = words.next().parse_value("second triangle vertex index")?;
-        let c = words.next().parse_value("third triangle vertex index")?;
+    fn exclusion_fields(
+        tokens: &[super::Token],
+        line_no: usize,
+    ) -> Result<(u32, Exclusion), TsiError> {
+        let found_idx = field(tokens, 0, "exclusion index", line_no)?;
+        let vertex_index = field(tokens, 1, "exclusion vertex index", line_no)?;
+        let radius = field(tokens, 2, "exclusion radius", line_no)?;
+        Ok((found_idx, Exclusion { vertex_index, radius }))
+    }
 
-        Ok(Triangle { vertices: [a, b, c] })
+    pub fn parse_vertex_line(
+        line: &str,
+        line_no: usize,
+        expected_idx: u32,
+    ) -> Result<Vertex, TsiError> {
+        let (found_idx, vertex) = vertex_fields(&tokenize(line), line_no)?;
+        at(line_no, check_index("vertex", found_idx, expected_idx))?;
+        Ok(vertex)
     }
 
-    pub fn parse_inclusion_line(line: &str, expected_idx: u32) -> Result<Inclusion, TsiError> {
-        let mut words = line.split_whitespace();
-        let found_idx = words.next().parse_value("inclusion index")?;
-        check_index("inclusion", found_idx, expected_idx)?;
+    pub fn parse_triangle_line(
+        line: &str,
+        line_no: usize,
+        expected_idx: u32,
+    ) -> Result<Triangle, TsiError> {
+        let (found_idx, triangle) = triangle_fields(&tokenize(line), line_no)?;
+        at(line_no, check_index("triangle", found_idx, expected_idx))?;
+        Ok(triangle)
+    }
 
-        let ty = words.next().parse_value("inclusion type")?;
-        let vertex_index = words.next().parse_value("inclusion vertex index")?;
-        let x: f32 = words.next().parse_value("inclusion vector x")?;
-        let y: f32 = words.next().parse_value("inclusion vector y")?;
-        let norm = f32::sqrt(x.powi(2) + y.powi(2));
-        let vector = if norm > 0.0 { [x / norm, y / norm] } else { [0.0, 0.0] };
+    pub fn parse_inclusion_line(
+        line: &str,
+        line_no: usize,
+        expected_idx: u32,
+    ) -> Result<Inclusion, TsiError> {
+        let (found_idx, inclusion) = inclusion_fields(&tokenize(line), line_no)?;
+        at(line_no, check_index("inclusion", found_idx, expected_idx))?;
+        Ok(inclusion)
+    }
 
-        Ok(Inclusion { ty, vertex_index, vector })
+    pub fn parse_exclusion_line(
+        line: &str,
+        line_no: usize,
+        expected_idx: u32,
+    ) -> Result<Exclusion, TsiError> {
+        let (found_idx, exclusion) = exclusion_fields(&tokenize(line), line_no)?;
+        at(line_no, check_index("exclusion", found_idx, expected_idx))?;
+        Ok(exclusion)
     }
 
-    pub fn parse_exclusion_line(line: &str, expected_idx: u32) -> Result<Exclusion, TsiError> {
-        let mut words = line.split_whitespace();
-        let found_idx = words.next().parse_value("exclusion index")?;
-        check_index("exclusion", found_idx, expected_idx)?;
+    /// Parses a record's declared index alongside its data, without
+    /// requiring the index to match an expected position. Used by
+    /// [`super::Tsi::parse_collect`], which tolerates out-of-order or
+    /// duplicate declarations and reports them instead of bailing.
+    pub fn parse_vertex_record(line: &str, line_no: usize) -> Result<(u32, Vertex), TsiError> {
+        vertex_fields(&tokenize(line), line_no)
+    }
+
+    pub fn parse_triangle_record(line: &str, line_no: usize) -> Result<(u32, Triangle), TsiError> {
+        triangle_fields(&tokenize(line), line_no)
+    }
 
-        let vertex_index = words.next().parse_value("exclusion vertex index")?;
-        let radius = words.next().parse_value("exclusion radius")?;
+    pub fn parse_inclusion_record(
+        line: &str,
+        line_no: usize,
+    ) -> Result<(u32, Inclusion), TsiError> {
+        inclusion_fields(&tokenize(line), line_no)
+    }
 
-        Ok(Exclusion { vertex_index, radius })
+    pub fn parse_exclusion_record(
+        line: &str,
+        line_no: usize,
+    ) -> Result<(u32, Exclusion), TsiError> {
+        exclusion_fields(&tokenize(line), line_no)
     }
 }
 
@@ -171,6 +409,21 @@ pub trait ReadTsi {
 
 impl ReadTsi for Tsi {
     fn parse(reader: impl Read) -> Result<Self, TsiError> {
+        Tsi::parse_with(reader, ParseOptions::default()).map(|(tsi, _skipped)| tsi)
+    }
+}
+
+impl Tsi {
+    /// Parses a `.tsi` file under explicit [`ParseOptions`].
+    ///
+    /// Blank lines and `#`-prefixed comments (whole-line or trailing) are
+    /// always tolerated. In lenient mode (`strict: false`), an unrecognized
+    /// section keyword is skipped rather than rejected; the returned `Vec`
+    /// lists the `(line, text)` of every line skipped this way.
+    pub fn parse_with(
+        reader: impl Read,
+        options: ParseOptions,
+    ) -> Result<(Self, Vec<(usize, String)>), TsiError> {
         let reader = BufReader::new(reader);
         let mut lines = reader.lines();
 
@@ -180,69 +433,92 @@ impl ReadTsi for Tsi {
         let mut triangles = Vec::new();
         let mut inclusions = Vec::new();
         let mut exclusions = Vec::new();
+        let mut skipped = Vec::new();
 
-        while let Some(line_result) = lines.next() {
-            let line = line_result?;
+        let mut line_no = 0;
+        while let Some((header_line_no, line)) = next_line(&mut lines, &mut line_no)? {
             let mut words = line.split_whitespace();
             let keyword = match words.next() {
                 Some(k) => k,
-                None => return Err(missing_item_value("section keyword")),
+                None => return at(header_line_no, Err(missing_item_value("section keyword"))),
             };
 
+            // Header-level fields (the keyword's own line) are attributed to
+            // `header_line_no`; per-item sub-lines are attributed to their
+            // own line by `items::parse_*_line`.
             match keyword {
                 "version" => {
-                    version =
-                        Some(words.next().ok_or(missing_item_value("version tag"))?.to_string());
+                    version = Some(at(
+                        header_line_no,
+                        words
+                            .next()
+                            .ok_or(missing_item_value("version tag"))
+                            .map(|v| v.to_string()),
+                    )?);
                 }
                 "box" => {
-                    let x = words.next().parse_value("box x")?;
-                    let y = words.next().parse_value("box y")?;
-                    let z = words.next().parse_value("box z")?;
-                    dimensions = Some([x, y, z]);
+                    dimensions = at(header_line_no, (|| {
+                        let x = words.next().parse_value("box x")?;
+                        let y = words.next().parse_value("box y")?;
+                        let z = words.next().parse_value("box z")?;
+                        Ok(Some([x, y, z]))
+                    })())?;
                 }
                 "vertex" => {
-                    let n: u32 = words.next().parse_value("vertex count")?;
+                    let n: u32 = at(header_line_no, words.next().parse_value("vertex count"))?;
                     vertices = Vec::with_capacity(n as usize);
                     for idx in 0..n {
-                        let line =
-                            lines.next().ok_or(TsiError::Missing(MissingItem::Vertex(idx)))??;
-                        let vertex = items::parse_vertex_line(&line, idx)?;
+                        let Some((item_line_no, line)) = next_line(&mut lines, &mut line_no)?
+                        else {
+                            return Err(TsiError::Missing(MissingItem::Vertex(idx)));
+                        };
+                        let vertex = items::parse_vertex_line(&line, item_line_no, idx)?;
                         vertices.push(vertex);
                     }
                 }
                 "triangle" => {
-                    let n: u32 = words.next().parse_value("triangle count")?;
+                    let n: u32 = at(header_line_no, words.next().parse_value("triangle count"))?;
                     triangles = Vec::with_capacity(n as usize);
                     for idx in 0..n {
-                        let line =
-                            lines.next().ok_or(TsiError::Missing(MissingItem::Triangle(idx)))??;
-                        let triangle = items::parse_triangle_line(&line, idx)?;
+                        let Some((item_line_no, line)) = next_line(&mut lines, &mut line_no)?
+                        else {
+                            return Err(TsiError::Missing(MissingItem::Triangle(idx)));
+                        };
+                        let triangle = items::parse_triangle_line(&line, item_line_no, idx)?;
                         triangles.push(triangle);
                     }
                 }
                 "inclusion" => {
-                    let n: u32 = words.next().parse_value("inclusion count")?;
+                    let n: u32 = at(header_line_no, words.next().parse_value("inclusion count"))?;
                     inclusions = Vec::with_capacity(n as usize);
                     for idx in 0..n {
-                        let line = lines
-                            .next()
-                            .ok_or(TsiError::Missing(MissingItem::Inclusion(idx)))??;
-                        let inclusion = items::parse_inclusion_line(&line, idx)?;
+                        let Some((item_line_no, line)) = next_line(&mut lines, &mut line_no)?
+                        else {
+                            return Err(TsiError::Missing(MissingItem::Inclusion(idx)));
+                        };
+                        let inclusion = items::parse_inclusion_line(&line, item_line_no, idx)?;
                         inclusions.push(inclusion);
                     }
                 }
                 "exclusion" => {
-                    let n: u32 = words.next().parse_value("exclusion count")?;
+                    let n: u32 = at(header_line_no, words.next().parse_value("exclusion count"))?;
                     exclusions = Vec::with_capacity(n as usize);
                     for idx in 0..n {
-                        let line = lines
-                            .next()
-                            .ok_or(TsiError::Missing(MissingItem::Exclusion(idx)))??;
-                        let exclusion = items::parse_exclusion_line(&line, idx)?;
+                        let Some((item_line_no, line)) = next_line(&mut lines, &mut line_no)?
+                        else {
+                            return Err(TsiError::Missing(MissingItem::Exclusion(idx)));
+                        };
+                        let exclusion = items::parse_exclusion_line(&line, item_line_no, idx)?;
                         exclusions.push(exclusion);
                     }
                 }
-                unknown => return Err(TsiError::UnexpectedKeyword(unknown.to_string())),
+                unknown if options.strict => {
+                    return at(
+                        header_line_no,
+                        Err(TsiError::UnexpectedKeyword(unknown.to_string())),
+                    )
+                }
+                _unknown => skipped.push((header_line_no, line.clone())),
             }
         }
 
@@ -254,7 +530,244 @@ impl ReadTsi for Tsi {
 
         let dimensions = dimensions.ok_or(TsiError::Missing(MissingItem::Definition("box")))?;
 
-        Ok(Tsi { dimensions, vertices, triangles, inclusions, exclusions })
+        let tsi = Tsi { dimensions, vertices, triangles, inclusions, exclusions };
+        Ok((tsi, skipped))
+    }
+
+    /// Parses a `.tsi` file, continuing past recoverable errors instead of
+    /// stopping at the first one, so every problem in a file can be fixed in
+    /// one pass rather than one `parse` call per mistake.
+    ///
+    /// A malformed record is dropped (its error is still reported) and
+    /// parsing continues with the next one. Once all records are read, every
+    /// `Triangle.vertices`, `Inclusion.vertex_index`, and
+    /// `Exclusion.vertex_index` is checked against `0..vertices.len()`, and
+    /// declared record indices are checked for duplicates within their
+    /// section, including a triangle naming the same vertex more than once.
+    ///
+    /// Returns `None` in place of the `Tsi` only when no valid `box` line was
+    /// read (it is missing or malformed, or an I/O error ended reading before
+    /// it); otherwise the best effort result is returned alongside every
+    /// error encountered.
+    pub fn parse_collect(reader: impl Read) -> (Option<Self>, Vec<TsiError>) {
+        let reader = BufReader::new(reader);
+        let mut lines = reader.lines();
+        let mut errors = Vec::new();
+
+        let mut version = None;
+        let mut dimensions = None;
+        let mut vertices = Vec::new();
+        let mut triangles = Vec::new();
+        let mut inclusions = Vec::new();
+        let mut exclusions = Vec::new();
+
+        let mut line_no = 0;
+        loop {
+            let next = match next_line(&mut lines, &mut line_no) {
+                Ok(Some(next)) => next,
+                Ok(None) => break,
+                Err(e) => {
+                    errors.push(TsiError::from(e));
+                    break;
+                }
+            };
+            let (header_line_no, line) = next;
+            let mut words = line.split_whitespace();
+            let Some(keyword) = words.next() else {
+                errors.push(TsiError::At {
+                    line: header_line_no,
+                    offset: None,
+                    source: Box::new(missing_item_value("section keyword")),
+                });
+                continue;
+            };
+
+            match keyword {
+                "version" => match words.next() {
+                    Some(v) => version = Some(v.to_string()),
+                    None => errors.push(TsiError::At {
+                        line: header_line_no,
+                        offset: None,
+                        source: Box::new(missing_item_value("version tag")),
+                    }),
+                },
+                "box" => {
+                    match (|| {
+                        let x = words.next().parse_value("box x")?;
+                        let y = words.next().parse_value("box y")?;
+                        let z = words.next().parse_value("box z")?;
+                        Ok::<_, TsiError>([x, y, z])
+                    })() {
+                        Ok(d) => dimensions = Some(d),
+                        Err(source) => {
+                            errors.push(TsiError::At { line: header_line_no, offset: None, source: Box::new(source) })
+                        }
+                    }
+                }
+                "vertex" => {
+                    let n: u32 = match at(header_line_no, words.next().parse_value("vertex count")) {
+                        Ok(n) => n,
+                        Err(e) => {
+                            errors.push(e);
+                            continue;
+                        }
+                    };
+                    let mut raw = Vec::new();
+                    for _ in 0..n {
+                        let (item_line_no, line) = match next_line(&mut lines, &mut line_no) {
+                            Ok(Some(next)) => next,
+                            Ok(None) => break,
+                            Err(e) => {
+                                errors.push(TsiError::from(e));
+                                break;
+                            }
+                        };
+                        match items::parse_vertex_record(&line, item_line_no) {
+                            Ok((declared_idx, vertex)) => raw.push((declared_idx, item_line_no, vertex)),
+                            Err(e) => errors.push(e),
+                        }
+                    }
+                    vertices = place_by_declared_index(n, "vertex", raw, &mut errors);
+                }
+                "triangle" => {
+                    let n: u32 = match at(header_line_no, words.next().parse_value("triangle count")) {
+                        Ok(n) => n,
+                        Err(e) => {
+                            errors.push(e);
+                            continue;
+                        }
+                    };
+                    let mut raw = Vec::new();
+                    for _ in 0..n {
+                        let (item_line_no, line) = match next_line(&mut lines, &mut line_no) {
+                            Ok(Some(next)) => next,
+                            Ok(None) => break,
+                            Err(e) => {
+                                errors.push(TsiError::from(e));
+                                break;
+                            }
+                        };
+                        match items::parse_triangle_record(&line, item_line_no) {
+                            Ok((declared_idx, triangle)) => {
+                                let [a, b, c] = triangle.vertices;
+                                if a == b || b == c || a == c {
+                                    let index = if a == b || a == c { a } else { b };
+                                    errors.push(TsiError::At {
+                                        line: item_line_no,
+                                        offset: None,
+                                        source: Box::new(TsiError::DuplicateIndex {
+                                            thing: "triangle vertex",
+                                            index,
+                                        }),
+                                    });
+                                }
+                                raw.push((declared_idx, item_line_no, triangle));
+                            }
+                            Err(e) => errors.push(e),
+                        }
+                    }
+                    triangles = place_by_declared_index(n, "triangle", raw, &mut errors);
+                }
+                "inclusion" => {
+                    let n: u32 =
+                        match at(header_line_no, words.next().parse_value("inclusion count")) {
+                            Ok(n) => n,
+                            Err(e) => {
+                                errors.push(e);
+                                continue;
+                            }
+                        };
+                    let mut raw = Vec::new();
+                    for _ in 0..n {
+                        let (item_line_no, line) = match next_line(&mut lines, &mut line_no) {
+                            Ok(Some(next)) => next,
+                            Ok(None) => break,
+                            Err(e) => {
+                                errors.push(TsiError::from(e));
+                                break;
+                            }
+                        };
+                        match items::parse_inclusion_record(&line, item_line_no) {
+                            Ok((declared_idx, inclusion)) => raw.push((declared_idx, item_line_no, inclusion)),
+                            Err(e) => errors.push(e),
+                        }
+                    }
+                    inclusions = place_by_declared_index(n, "inclusion", raw, &mut errors);
+                }
+                "exclusion" => {
+                    let n: u32 =
+                        match at(header_line_no, words.next().parse_value("exclusion count")) {
+                            Ok(n) => n,
+                            Err(e) => {
+                                errors.push(e);
+                                continue;
+                            }
+                        };
+                    let mut raw = Vec::new();
+                    for _ in 0..n {
+                        let (item_line_no, line) = match next_line(&mut lines, &mut line_no) {
+                            Ok(Some(next)) => next,
+                            Ok(None) => break,
+                            Err(e) => {
+                                errors.push(TsiError::from(e));
+                                break;
+                            }
+                        };
+                        match items::parse_exclusion_record(&line, item_line_no) {
+                            Ok((declared_idx, exclusion)) => raw.push((declared_idx, item_line_no, exclusion)),
+                            Err(e) => errors.push(e),
+                        }
+                    }
+                    exclusions = place_by_declared_index(n, "exclusion", raw, &mut errors);
+                }
+                unknown => errors.push(TsiError::At {
+                    line: header_line_no,
+                    offset: None,
+                    source: Box::new(TsiError::UnexpectedKeyword(unknown.to_string())),
+                }),
+            }
+        }
+
+        match &version {
+            Some(v) if v == EXPECTED_VERSION => {}
+            Some(found) => errors.push(TsiError::InvalidVersion(found.clone())),
+            None => errors.push(TsiError::Missing(MissingItem::Definition("version"))),
+        }
+
+        let Some(dimensions) = dimensions else {
+            errors.push(TsiError::Missing(MissingItem::Definition("box")));
+            return (None, errors);
+        };
+
+        let len = vertices.len();
+        for triangle in &triangles {
+            for index in triangle.vertices {
+                if index as usize >= len {
+                    errors.push(TsiError::IndexOutOfRange { thing: "triangle vertex", index, len });
+                }
+            }
+        }
+        for inclusion in &inclusions {
+            if inclusion.vertex_index as usize >= len {
+                errors.push(TsiError::IndexOutOfRange {
+                    thing: "inclusion vertex",
+                    index: inclusion.vertex_index,
+                    len,
+                });
+            }
+        }
+        for exclusion in &exclusions {
+            if exclusion.vertex_index as usize >= len {
+                errors.push(TsiError::IndexOutOfRange {
+                    thing: "exclusion vertex",
+                    index: exclusion.vertex_index,
+                    len,
+                });
+            }
+        }
+
+        let tsi = Tsi { dimensions, vertices, triangles, inclusions, exclusions };
+        (Some(tsi), errors)
     }
 }
 
@@ -289,7 +802,7 @@ inclusion 1
     fn normalization_safety() {
         // Testing 0.0 0.0 vector to ensure no NaN propagation.
         let zero_vector_line = "0 1 2 0.0 0.0";
-        let inclusion = items::parse_inclusion_line(zero_vector_line, 0).unwrap();
+        let inclusion = items::parse_inclusion_line(zero_vector_line, 1, 0).unwrap();
         assert_eq!(inclusion.vector, [0.0, 0.0]);
         assert!(!inclusion.vector[0].is_nan());
     }
@@ -297,11 +810,17 @@ inclusion 1
     #[test]
     fn index_mismatch() {
         let bad_index_line = "5 21.4 33.8 32.7 0"; // Expected 0, found 5.
-        let result = items::parse_vertex_line(bad_index_line, 0);
+        let result = items::parse_vertex_line(bad_index_line, 4, 0);
         match result {
-            Err(TsiError::IndexMismatch { found, expected, .. }) => {
-                assert_eq!(found, 5);
-                assert_eq!(expected, 0);
+            Err(TsiError::At { line, source, .. }) => {
+                assert_eq!(line, 4);
+                match *source {
+                    TsiError::IndexMismatch { found, expected, .. } => {
+                        assert_eq!(found, 5);
+                        assert_eq!(expected, 0);
+                    }
+                    _ => panic!("Expected IndexMismatch error"),
+                }
             }
             _ => panic!("Expected IndexMismatch error"),
         }
@@ -313,4 +832,111 @@ inclusion 1
         let result = Tsi::parse(Cursor::new(invalid_version));
         assert!(matches!(result, Err(TsiError::InvalidVersion(_))));
     }
+
+    #[test]
+    fn comments_and_blank_lines_are_tolerated() {
+        let src = "\
+# a membrane, hand-edited
+version 1.1
+
+box 50.0 50.0 50.0 # nm
+vertex 1
+0 21.4 33.8 32.7 0
+triangle 0
+inclusion 0";
+        let tsi = Tsi::parse(Cursor::new(src)).unwrap();
+        assert_eq!(tsi.dimensions, [50.0, 50.0, 50.0]);
+        assert_eq!(tsi.vertices.len(), 1);
+    }
+
+    #[test]
+    fn strict_mode_rejects_unknown_keyword() {
+        let src = VALID_TSI.replace("triangle 1", "widget 1\ntriangle 1");
+        let result = Tsi::parse_with(Cursor::new(src), ParseOptions { strict: true });
+        assert!(matches!(result, Err(TsiError::At { source, .. }) if matches!(*source, TsiError::UnexpectedKeyword(_))));
+    }
+
+    #[test]
+    fn lenient_mode_collects_unknown_keyword() {
+        let src = VALID_TSI.replace("triangle 1", "widget 1\ntriangle 1");
+        let (tsi, skipped) =
+            Tsi::parse_with(Cursor::new(src), ParseOptions { strict: false }).unwrap();
+        assert_eq!(tsi.triangles.len(), 1);
+        assert_eq!(skipped, vec![(7, "widget 1".to_string())]);
+    }
+
+    #[test]
+    fn parse_collect_succeeds_without_errors_on_valid_input() {
+        let (tsi, errors) = Tsi::parse_collect(Cursor::new(VALID_TSI));
+        assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+        let tsi = tsi.expect("a valid file should always produce a Tsi");
+        assert_eq!(tsi.vertices.len(), 3);
+        assert_eq!(tsi.triangles.len(), 1);
+    }
+
+    #[test]
+    fn parse_collect_reports_out_of_range_vertex_index() {
+        let src = VALID_TSI.replace("0 1 0 2 1", "0 1 0 9 1");
+        let (tsi, errors) = Tsi::parse_collect(Cursor::new(src));
+        let tsi = tsi.expect("an out-of-range index is recoverable");
+        assert_eq!(tsi.triangles.len(), 1);
+        assert!(
+            errors
+                .iter()
+                .any(|e| matches!(e, TsiError::IndexOutOfRange { thing: "triangle vertex", index: 9, .. })),
+            "expected an IndexOutOfRange error, got: {errors:?}"
+        );
+    }
+
+    #[test]
+    fn parse_collect_reports_degenerate_triangle() {
+        let src = VALID_TSI.replace("0 1 0 2 1", "0 1 1 2 1");
+        let (tsi, errors) = Tsi::parse_collect(Cursor::new(src));
+        let tsi = tsi.expect("a degenerate triangle is still recoverable");
+        assert_eq!(tsi.triangles.len(), 1);
+        assert!(
+            errors.iter().any(|e| matches!(
+                e,
+                TsiError::At { source, .. }
+                    if matches!(**source, TsiError::DuplicateIndex { thing: "triangle vertex", .. })
+            )),
+            "expected a DuplicateIndex error, got: {errors:?}"
+        );
+    }
+
+    #[test]
+    fn parse_collect_reorders_out_of_order_vertex_declarations() {
+        let src = VALID_TSI.replace(
+            "0 21.4 33.8 32.7 0\n1 38.1 26.1 32.3 0\n2 40.9 24.2 19.9 0",
+            "1 38.1 26.1 32.3 0\n0 21.4 33.8 32.7 0\n2 40.9 24.2 19.9 0",
+        );
+        let (tsi, errors) = Tsi::parse_collect(Cursor::new(src));
+        let tsi = tsi.expect("out-of-order declarations are recoverable");
+        // Despite the file order, each vertex ends up in the slot its own
+        // declared index names, not the order it happened to appear in.
+        assert_eq!(tsi.vertices[0].position, [21.4, 33.8, 32.7]);
+        assert_eq!(tsi.vertices[1].position, [38.1, 26.1, 32.3]);
+        assert_eq!(
+            errors
+                .iter()
+                .filter(|e| matches!(
+                    e,
+                    TsiError::At { source, .. }
+                        if matches!(**source, TsiError::IndexMismatch { thing: "vertex", .. })
+                ))
+                .count(),
+            2,
+            "expected an IndexMismatch for both swapped declarations, got: {errors:?}"
+        );
+    }
+
+    #[test]
+    fn parse_collect_continues_past_a_malformed_vertex() {
+        let src = VALID_TSI.replace("1 38.1 26.1 32.3 0", "1 not-a-number 26.1 32.3 0");
+        let (tsi, errors) = Tsi::parse_collect(Cursor::new(src));
+        let tsi = tsi.expect("a malformed record should not abort the whole parse");
+        // The malformed vertex is dropped, so only the other two remain.
+        assert_eq!(tsi.vertices.len(), 2);
+        assert!(!errors.is_empty());
+    }
 }
@@ -2,7 +2,7 @@ use crate::{Exclusion, Inclusion, Triangle, Tsi, Vertex};
 use std::io::{Result, Write};
 
 /// Round values to sufficient spatial precision.
-const fn round_to_precision(v: f32) -> f32 {
+pub(crate) const fn round_to_precision(v: f32) -> f32 {
     // A 1/1000th of a nanometer ought to be enough.
     const PRECISION: f32 = 1e3;
     (v * PRECISION).round() / PRECISION